@@ -20,7 +20,7 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> ContractResult<Response> {
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-    execute::init(deps.storage, &msg.pubkey)
+    execute::init(deps.storage, &msg.signers, msg.threshold)
 }
 
 #[entry_point]
@@ -28,16 +28,14 @@ pub fn sudo(deps: DepsMut, env: Env, msg: AccountSudoMsg) -> ContractResult<Resp
     match msg {
         AccountSudoMsg::BeforeTx {
             msgs,
-            pubkey,
+            signatures,
             sign_bytes,
-            signature,
         } => execute::before_tx(
-            deps.as_ref(),
+            deps,
             &env.block,
             &msgs,
-            pubkey.as_ref(),
+            &signatures,
             &sign_bytes,
-            &signature,
         ),
         AccountSudoMsg::AfterTx {
             ..
@@ -56,19 +54,57 @@ pub fn execute(
         ExecuteMsg::Grant {
             type_url,
             grantee,
+            grantee_kind,
+            expiry,
+            max_calls,
+            spend_limit,
+        } => execute::grant(
+            deps,
+            env,
+            info,
+            type_url,
+            grantee,
+            grantee_kind,
             expiry,
-        } => execute::grant(deps, env, info, type_url, grantee, expiry),
+            max_calls,
+            spend_limit,
+        ),
         ExecuteMsg::Revoke {
             type_url,
             grantee,
         } => execute::revoke(deps, env, info, type_url, grantee),
+        ExecuteMsg::AddSigner {
+            pubkey,
+            weight,
+        } => execute::add_signer(deps, env, info, pubkey, weight),
+        ExecuteMsg::RemoveSigner {
+            pubkey,
+        } => execute::remove_signer(deps, env, info, pubkey),
+        ExecuteMsg::UpdateThreshold {
+            threshold,
+        } => execute::update_threshold(deps, env, info, threshold),
+        ExecuteMsg::GrantAll {
+            grantee,
+            grantee_kind,
+            expiry,
+        } => execute::grant_all(deps, env, info, grantee, grantee_kind, expiry),
+        ExecuteMsg::RevokeAll {
+            grantee,
+        } => execute::revoke_all(deps, env, info, grantee),
+        ExecuteMsg::GrantBatch {
+            grants,
+        } => execute::grant_batch(deps, env, info, grants),
+        ExecuteMsg::RevokeBatch {
+            revocations,
+        } => execute::revoke_batch(deps, env, info, revocations),
     }
 }
 
 #[entry_point]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::Pubkey {} => to_binary(&query::pubkey(deps.storage)?),
+        QueryMsg::Signers {} => to_binary(&query::signers(deps.storage)?),
+        QueryMsg::Threshold {} => to_binary(&query::threshold(deps.storage)?),
         QueryMsg::Grant {
             type_url,
             grantee,
@@ -77,5 +113,12 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         } => to_binary(&query::grants(deps.storage, start_after, limit)?),
+        QueryMsg::Operator {
+            grantee,
+        } => to_binary(&query::operator(deps.storage, grantee)?),
+        QueryMsg::Operators {
+            start_after,
+            limit,
+        } => to_binary(&query::operators(deps.storage, start_after, limit)?),
     }
 }
\ No newline at end of file
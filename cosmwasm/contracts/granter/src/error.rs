@@ -0,0 +1,55 @@
+use cosmwasm_std::{StdError, VerificationError};
+use thiserror::Error;
+
+pub type ContractResult<T> = Result<T, ContractError>;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Verification(#[from] VerificationError),
+
+    #[error("unauthorized")]
+    Unauthorized,
+
+    #[error("invalid signature")]
+    InvalidSignature,
+
+    #[error("grant is already expired")]
+    NewGrantExpired,
+
+    #[error("grant not found for type_url {type_url} and grantee {grantee}")]
+    GrantNotFound { type_url: String, grantee: String },
+
+    #[error("grant for type_url {type_url} and grantee {grantee} has expired")]
+    GrantExpired { type_url: String, grantee: String },
+
+    #[error("duplicate signer {pubkey}")]
+    DuplicateSigner { pubkey: String },
+
+    #[error("duplicate signature from {pubkey}")]
+    DuplicateSignature { pubkey: String },
+
+    #[error("signer {pubkey} not found")]
+    SignerNotFound { pubkey: String },
+
+    #[error("threshold must be greater than zero")]
+    ThresholdZero,
+
+    #[error("threshold {threshold} exceeds total signer weight {total_weight}")]
+    ThresholdExceedsWeight { threshold: u64, total_weight: u64 },
+
+    #[error("total signer weight overflowed u64")]
+    WeightOverflow,
+
+    #[error("accumulated signature weight {weight} is below the threshold of {threshold}")]
+    InsufficientWeight { weight: u64, threshold: u64 },
+
+    #[error("grant for type_url {type_url} and grantee {grantee} has no calls remaining")]
+    GrantCallsExhausted { type_url: String, grantee: String },
+
+    #[error("grant for type_url {type_url} and grantee {grantee} would exceed its spend limit")]
+    GrantSpendLimitExceeded { type_url: String, grantee: String },
+}
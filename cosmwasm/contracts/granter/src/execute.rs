@@ -1,43 +1,120 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use abstract_account::StargateMsg;
-use cosmwasm_std::{Addr, Binary, Deps, Response, Storage, DepsMut, Env, MessageInfo, BlockInfo};
+use cosmwasm_std::{
+    Addr, Api, Binary, BlockInfo, DepsMut, Env, MessageInfo, Order, Response, Storage,
+};
 use cw_utils::Expiration;
 use sha2::{Digest, Sha256};
 
 use crate::{
     error::{ContractError, ContractResult},
-    state::{PUBKEY, GRANTS}, msg::Grant,
+    msg::{Grant, GrantItem, OperatorGrant, PubKey, PubKeyKind, RevokeItem, Signer, SpendLimit},
+    proto,
+    state::{GRANTS, OPERATORS, SIGNERS, THRESHOLD},
 };
 
-pub fn init(store: &mut dyn Storage, pubkey: &Binary) -> ContractResult<Response> {
-    PUBKEY.save(store, pubkey)?;
+pub fn init(
+    store: &mut dyn Storage,
+    signers: &[(PubKey, u64)],
+    threshold: u64,
+) -> ContractResult<Response> {
+    let total_weight = signers
+        .iter()
+        .try_fold(0u64, |acc, (_, weight)| acc.checked_add(*weight))
+        .ok_or(ContractError::WeightOverflow)?;
+    assert_valid_threshold(threshold, total_weight)?;
+
+    let mut seen = BTreeSet::new();
+
+    for (pubkey, weight) in signers {
+        if !seen.insert(pubkey.key.clone()) {
+            return Err(ContractError::DuplicateSigner {
+                pubkey: pubkey.key.to_base64(),
+            });
+        }
+
+        SIGNERS.save(
+            store,
+            &pubkey.key,
+            &Signer {
+                kind: pubkey.kind,
+                weight: *weight,
+            },
+        )?;
+    }
+
+    THRESHOLD.save(store, &threshold)?;
 
     Ok(Response::new()
         .add_attribute("method", "init")
-        .add_attribute("pubkey", pubkey.to_base64()))
+        .add_attribute("signers", signers.len().to_string())
+        .add_attribute("threshold", threshold.to_string()))
 }
 
 pub fn before_tx(
-    deps: Deps,
+    deps: DepsMut,
     block: &BlockInfo,
     msgs: &[StargateMsg],
-    pubkey: Option<&Binary>,
+    signatures: &[(Binary, Binary)],
     sign_bytes: &Binary,
-    signature: &Binary,
 ) -> ContractResult<Response> {
     let sign_bytes_hash = sha256(sign_bytes);
-    let self_pubkey = PUBKEY.load(deps.storage)?;
-    let pubkey = pubkey.unwrap_or(&self_pubkey);
+    let DepsMut { storage, api, .. } = deps;
+    let threshold = THRESHOLD.load(storage)?;
 
-    if *pubkey != self_pubkey {
-        assert_has_grant(deps.storage, block, msgs, pubkey)?;
-    }
+    let mut seen = BTreeSet::new();
+    let mut weight = 0u64;
+
+    for (pubkey, signature) in signatures {
+        if !seen.insert(pubkey.clone()) {
+            return Err(ContractError::DuplicateSignature {
+                pubkey: pubkey.to_base64(),
+            });
+        }
+
+        if let Some(signer) = SIGNERS.may_load(storage, pubkey)? {
+            if verify_signature(api, signer.kind, &sign_bytes_hash, signature, pubkey)? {
+                weight = weight.saturating_add(signer.weight);
+            }
+            continue;
+        }
+
+        // not one of the account's own signers; it may be a delegate acting
+        // under a grant instead, which authorizes the tx on its own. A key
+        // that holds no grant at all is just a stray/extra signature, not an
+        // attempted delegate, so it's ignored rather than failing the tx.
+        let Some((grantee_kind, updated_grants)) = assert_has_grant(storage, block, msgs, pubkey)?
+        else {
+            continue;
+        };
+
+        if verify_signature(api, grantee_kind, &sign_bytes_hash, signature, pubkey)? {
+            // only spend down the grant's call/spend budget once the
+            // signature it authorizes has actually checked out
+            for (type_url, grant) in updated_grants {
+                if grant.max_calls == Some(0) {
+                    GRANTS.remove(storage, (&type_url, pubkey));
+                } else {
+                    GRANTS.save(storage, (&type_url, pubkey), &grant)?;
+                }
+            }
+
+            return Ok(Response::new()
+                .add_attribute("method", "before_tx")
+                .add_attribute("authorized_by", "grant"));
+        }
 
-    if !deps.api.secp256k1_verify(&sign_bytes_hash, signature, pubkey)? {
         return Err(ContractError::InvalidSignature);
     }
 
+    if weight < threshold {
+        return Err(ContractError::InsufficientWeight { weight, threshold });
+    }
+
     Ok(Response::new()
-        .add_attribute("method", "before_tx"))
+        .add_attribute("method", "before_tx")
+        .add_attribute("weight", weight.to_string()))
 }
 
 pub fn after_tx() -> ContractResult<Response> {
@@ -45,13 +122,81 @@ pub fn after_tx() -> ContractResult<Response> {
         .add_attribute("method", "after_tx"))
 }
 
+pub fn add_signer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pubkey: PubKey,
+    weight: u64,
+) -> ContractResult<Response> {
+    assert_self(&info.sender, &env.contract.address)?;
+
+    if SIGNERS.has(deps.storage, &pubkey.key) {
+        return Err(ContractError::DuplicateSigner {
+            pubkey: pubkey.key.to_base64(),
+        });
+    }
+
+    SIGNERS.save(
+        deps.storage,
+        &pubkey.key,
+        &Signer {
+            kind: pubkey.kind,
+            weight,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_signer")
+        .add_attribute("pubkey", pubkey.key.to_base64())
+        .add_attribute("weight", weight.to_string()))
+}
+
+pub fn remove_signer(deps: DepsMut, env: Env, info: MessageInfo, pubkey: Binary) -> ContractResult<Response> {
+    assert_self(&info.sender, &env.contract.address)?;
+
+    if !SIGNERS.has(deps.storage, &pubkey) {
+        return Err(ContractError::SignerNotFound {
+            pubkey: pubkey.to_base64(),
+        });
+    }
+
+    SIGNERS.remove(deps.storage, &pubkey);
+
+    // removing a signer can only shrink the total weight, so make sure the
+    // existing threshold is still reachable.
+    let total_weight = total_signer_weight(deps.storage)?;
+    let threshold = THRESHOLD.load(deps.storage)?;
+    assert_valid_threshold(threshold, total_weight)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_signer")
+        .add_attribute("pubkey", pubkey.to_base64()))
+}
+
+pub fn update_threshold(deps: DepsMut, env: Env, info: MessageInfo, threshold: u64) -> ContractResult<Response> {
+    assert_self(&info.sender, &env.contract.address)?;
+
+    let total_weight = total_signer_weight(deps.storage)?;
+    assert_valid_threshold(threshold, total_weight)?;
+
+    THRESHOLD.save(deps.storage, &threshold)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_threshold")
+        .add_attribute("threshold", threshold.to_string()))
+}
+
 pub fn grant(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     type_url: String,
     grantee: Binary,
+    grantee_kind: PubKeyKind,
     expiry: Option<Expiration>,
+    max_calls: Option<u64>,
+    spend_limit: Option<SpendLimit>,
 ) -> ContractResult<Response> {
     // only the account itself can make grants
     assert_self(&info.sender, &env.contract.address)?;
@@ -63,7 +208,16 @@ pub fn grant(
         }
     }
 
-    GRANTS.save(deps.storage, (&type_url, &grantee), &Grant { expiry })?;
+    GRANTS.save(
+        deps.storage,
+        (&type_url, &grantee),
+        &Grant {
+            grantee_kind,
+            expiry,
+            max_calls,
+            spend_limit,
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "grant")
@@ -91,19 +245,150 @@ pub fn revoke(
         .add_attribute("type_url", type_url))
 }
 
+pub fn grant_batch(deps: DepsMut, env: Env, info: MessageInfo, grants: Vec<GrantItem>) -> ContractResult<Response> {
+    // only the account itself can make grants
+    assert_self(&info.sender, &env.contract.address)?;
+
+    // validate every expiry up front so the batch is all-or-nothing
+    for item in &grants {
+        if let Some(expiry) = item.expiry.as_ref() {
+            if expiry.is_expired(&env.block) {
+                return Err(ContractError::NewGrantExpired);
+            }
+        }
+    }
+
+    let mut response = Response::new().add_attribute("method", "grant_batch");
+
+    for item in grants {
+        GRANTS.save(
+            deps.storage,
+            (&item.type_url, &item.grantee),
+            &Grant {
+                grantee_kind: item.grantee_kind,
+                expiry: item.expiry,
+                max_calls: item.max_calls,
+                spend_limit: item.spend_limit,
+            },
+        )?;
+
+        response = response
+            .add_attribute("grantee", item.grantee.to_base64())
+            .add_attribute("type_url", item.type_url);
+    }
+
+    Ok(response)
+}
+
+pub fn revoke_batch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    revocations: Vec<RevokeItem>,
+) -> ContractResult<Response> {
+    // only the account itself can revoke grants
+    assert_self(&info.sender, &env.contract.address)?;
+
+    let mut response = Response::new().add_attribute("method", "revoke_batch");
+
+    for item in revocations {
+        GRANTS.remove(deps.storage, (&item.type_url, &item.grantee));
+
+        response = response
+            .add_attribute("grantee", item.grantee.to_base64())
+            .add_attribute("type_url", item.type_url);
+    }
+
+    Ok(response)
+}
+
+pub fn grant_all(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    grantee: Binary,
+    grantee_kind: PubKeyKind,
+    expiry: Expiration,
+) -> ContractResult<Response> {
+    // only the account itself can make grants
+    assert_self(&info.sender, &env.contract.address)?;
+
+    if expiry.is_expired(&env.block) {
+        return Err(ContractError::NewGrantExpired);
+    }
+
+    OPERATORS.save(
+        deps.storage,
+        &grantee,
+        &OperatorGrant { grantee_kind, expiry },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "grant_all")
+        .add_attribute("granter", env.contract.address)
+        .add_attribute("grantee", grantee.to_base64()))
+}
+
+pub fn revoke_all(deps: DepsMut, env: Env, info: MessageInfo, grantee: Binary) -> ContractResult<Response> {
+    // only the account itself can revoke grants
+    assert_self(&info.sender, &env.contract.address)?;
+
+    OPERATORS.remove(deps.storage, &grantee);
+
+    Ok(Response::new()
+        .add_attribute("method", "revoke_all")
+        .add_attribute("granter", env.contract.address)
+        .add_attribute("grantee", grantee.to_base64()))
+}
+
+/// Checks that `grantee` holds a live, unexhausted grant for every message in
+/// `msgs`, and returns the grantee's key scheme together with the grants as
+/// they should be persisted after a successful signature check (counters
+/// decremented, spend limits debited). A grant whose calls reach zero is
+/// returned with `max_calls: Some(0)`; the caller removes it from storage.
+///
+/// Returns `Ok(None)` when `grantee` holds no grant at all covering any of
+/// `msgs` — such a key is a stray/extra signature rather than an attempted
+/// delegate, and the caller treats it as one to ignore rather than an error.
 fn assert_has_grant(
     store: &dyn Storage,
     block: &BlockInfo,
     msgs: &[StargateMsg],
     grantee: &Binary,
-) -> ContractResult<()> {
+) -> ContractResult<Option<(PubKeyKind, Vec<(String, Grant)>)>> {
+    // an unexpired operator grant authorizes every type_url on its own, so
+    // it short-circuits the per-type_url lookup below entirely.
+    if let Some(operator) = OPERATORS.may_load(store, grantee)? {
+        if !operator.expiry.is_expired(block) {
+            return Ok(Some((operator.grantee_kind, Vec::new())));
+        }
+    }
+
+    if !msgs
+        .iter()
+        .any(|msg| GRANTS.has(store, (msg.type_url.as_str(), grantee)))
+    {
+        return Ok(None);
+    }
+
+    let mut grantee_kind = None;
+    // messages sharing a type_url must decrement the *same* grant's call
+    // count / spend limit cumulatively, so each type_url's grant is loaded
+    // from storage at most once and all its messages apply to that one
+    // in-memory copy before it's persisted.
+    let mut grants: BTreeMap<String, Grant> = BTreeMap::new();
+
     for msg in msgs {
-        let Some(grant) = GRANTS.may_load(store, (&msg.type_url, grantee))? else {
-            return Err(ContractError::GrantNotFound {
-                type_url: msg.type_url.clone(),
-                grantee: grantee.to_base64(),
-            });
-        };
+        if !grants.contains_key(&msg.type_url) {
+            let Some(grant) = GRANTS.may_load(store, (&msg.type_url, grantee))? else {
+                return Err(ContractError::GrantNotFound {
+                    type_url: msg.type_url.clone(),
+                    grantee: grantee.to_base64(),
+                });
+            };
+            grants.insert(msg.type_url.clone(), grant);
+        }
+        let grant = grants.get_mut(&msg.type_url).expect("just inserted above");
 
         if let Some(expiry) = grant.expiry {
             if expiry.is_expired(block) {
@@ -113,11 +398,79 @@ fn assert_has_grant(
                 });
             }
         }
+
+        if let Some(max_calls) = grant.max_calls {
+            if max_calls == 0 {
+                return Err(ContractError::GrantCallsExhausted {
+                    type_url: msg.type_url.clone(),
+                    grantee: grantee.to_base64(),
+                });
+            }
+            grant.max_calls = Some(max_calls - 1);
+        }
+
+        if let Some(spend_limit) = grant.spend_limit.as_mut() {
+            let spent = proto::sum_coin_amount(&msg.value, &spend_limit.denom)?;
+            spend_limit.remaining = spend_limit.remaining.checked_sub(spent).map_err(|_| {
+                ContractError::GrantSpendLimitExceeded {
+                    type_url: msg.type_url.clone(),
+                    grantee: grantee.to_base64(),
+                }
+            })?;
+        }
+
+        grantee_kind = Some(grant.grantee_kind);
+    }
+
+    // `msgs` is non-empty here (an empty `msgs` returned `None` above); every grant found above
+    // carries the same grantee, so any one of them tells us its scheme.
+    Ok(Some((
+        grantee_kind.unwrap_or(PubKeyKind::Secp256k1),
+        grants.into_iter().collect(),
+    )))
+}
+
+fn assert_valid_threshold(threshold: u64, total_weight: u64) -> ContractResult<()> {
+    if threshold == 0 {
+        return Err(ContractError::ThresholdZero);
+    }
+
+    if threshold > total_weight {
+        return Err(ContractError::ThresholdExceedsWeight {
+            threshold,
+            total_weight,
+        });
     }
 
     Ok(())
 }
 
+fn total_signer_weight(store: &dyn Storage) -> ContractResult<u64> {
+    SIGNERS
+        .range(store, None, None, Order::Ascending)
+        .try_fold(0u64, |acc, item| {
+            let (_, signer) = item?;
+            acc.checked_add(signer.weight)
+                .ok_or(ContractError::WeightOverflow)
+        })
+}
+
+fn verify_signature(
+    api: &dyn Api,
+    kind: PubKeyKind,
+    sign_bytes_hash: &[u8],
+    signature: &Binary,
+    pubkey: &Binary,
+) -> ContractResult<bool> {
+    let valid = match kind {
+        PubKeyKind::Secp256k1 => api.secp256k1_verify(sign_bytes_hash, signature, pubkey)?,
+        PubKeyKind::Ed25519 => api.ed25519_verify(sign_bytes_hash, signature, pubkey)?,
+        PubKeyKind::Secp256r1 => api.secp256r1_verify(sign_bytes_hash, signature, pubkey)?,
+    };
+
+    Ok(valid)
+}
+
 fn assert_self(sender: &Addr, contract: &Addr) -> ContractResult<()> {
     if sender != contract {
         return Err(ContractError::Unauthorized);
@@ -130,4 +483,233 @@ fn sha256(msg: &[u8]) -> Vec<u8> {
     let mut hasher = Sha256::new();
     hasher.update(msg);
     hasher.finalize().to_vec()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, MockStorage};
+
+    use super::*;
+
+    fn pubkey(byte: u8) -> Binary {
+        Binary::from(vec![byte])
+    }
+
+    /// Encodes a single protobuf length-delimited field: `(field_number << 3
+    /// | 2)` key, varint length, then the raw bytes.
+    fn encode_bytes_field(field_number: u32, bytes: &[u8]) -> Vec<u8> {
+        let mut out = encode_varint(((field_number << 3) | 2) as u64);
+        out.extend(encode_varint(bytes.len() as u64));
+        out.extend_from_slice(bytes);
+        out
+    }
+
+    fn encode_varint(mut value: u64) -> Vec<u8> {
+        let mut out = Vec::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                return out;
+            }
+            out.push(byte | 0x80);
+        }
+    }
+
+    /// Encodes a standalone `Coin { denom: 1, amount: 2 }` message, wrapped as
+    /// a single top-level field the way [`proto::sum_coin_amount`] expects.
+    fn encode_coin_msg(denom: &str, amount: u128) -> Binary {
+        let mut coin = encode_bytes_field(1, denom.as_bytes());
+        coin.extend(encode_bytes_field(2, amount.to_string().as_bytes()));
+        Binary::from(encode_bytes_field(1, &coin))
+    }
+
+    fn stargate_msg(type_url: &str, value: Binary) -> StargateMsg {
+        StargateMsg {
+            type_url: type_url.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn assert_valid_threshold_rejects_zero() {
+        assert!(matches!(
+            assert_valid_threshold(0, 10),
+            Err(ContractError::ThresholdZero)
+        ));
+    }
+
+    #[test]
+    fn assert_valid_threshold_rejects_over_total_weight() {
+        assert!(matches!(
+            assert_valid_threshold(11, 10),
+            Err(ContractError::ThresholdExceedsWeight {
+                threshold: 11,
+                total_weight: 10
+            })
+        ));
+    }
+
+    #[test]
+    fn assert_valid_threshold_accepts_threshold_equal_to_total_weight() {
+        assert!(assert_valid_threshold(10, 10).is_ok());
+    }
+
+    #[test]
+    fn total_signer_weight_sums_all_signers() {
+        let mut store = MockStorage::new();
+        SIGNERS
+            .save(&mut store, &pubkey(1), &Signer { kind: PubKeyKind::Secp256k1, weight: 3 })
+            .unwrap();
+        SIGNERS
+            .save(&mut store, &pubkey(2), &Signer { kind: PubKeyKind::Ed25519, weight: 4 })
+            .unwrap();
+
+        assert_eq!(total_signer_weight(&store).unwrap(), 7);
+    }
+
+    #[test]
+    fn total_signer_weight_rejects_overflow() {
+        let mut store = MockStorage::new();
+        SIGNERS
+            .save(&mut store, &pubkey(1), &Signer { kind: PubKeyKind::Secp256k1, weight: u64::MAX })
+            .unwrap();
+        SIGNERS
+            .save(&mut store, &pubkey(2), &Signer { kind: PubKeyKind::Secp256k1, weight: 1 })
+            .unwrap();
+
+        assert!(matches!(
+            total_signer_weight(&store),
+            Err(ContractError::WeightOverflow)
+        ));
+    }
+
+    #[test]
+    fn before_tx_rejects_duplicate_signatures() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        THRESHOLD.save(deps.as_mut().storage, &1).unwrap();
+        SIGNERS
+            .save(deps.as_mut().storage, &pubkey(1), &Signer { kind: PubKeyKind::Secp256k1, weight: 1 })
+            .unwrap();
+
+        let signatures = vec![
+            (pubkey(1), Binary::from(vec![0u8; 64])),
+            (pubkey(1), Binary::from(vec![0u8; 64])),
+        ];
+        let sign_bytes = Binary::from(b"tx".to_vec());
+
+        let err = before_tx(deps.as_mut(), &env.block, &[], &signatures, &sign_bytes).unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateSignature { .. }));
+    }
+
+    #[test]
+    fn assert_has_grant_returns_none_for_key_with_no_grant_at_all() {
+        let store = MockStorage::new();
+        let msgs = [stargate_msg("/cosmos.bank.v1beta1.MsgSend", Binary::default())];
+
+        let result = assert_has_grant(&store, &mock_env().block, &msgs, &pubkey(9)).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn assert_has_grant_short_circuits_on_operator_grant() {
+        let mut store = MockStorage::new();
+        OPERATORS
+            .save(
+                &mut store,
+                &pubkey(1),
+                &OperatorGrant {
+                    grantee_kind: PubKeyKind::Secp256k1,
+                    expiry: Expiration::Never {},
+                },
+            )
+            .unwrap();
+
+        let msgs = [stargate_msg("/cosmos.bank.v1beta1.MsgSend", Binary::default())];
+        let (kind, updates) = assert_has_grant(&store, &mock_env().block, &msgs, &pubkey(1))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(kind, PubKeyKind::Secp256k1);
+        assert!(updates.is_empty());
+    }
+
+    #[test]
+    fn assert_has_grant_accumulates_decrements_across_repeated_type_urls() {
+        let mut store = MockStorage::new();
+        GRANTS
+            .save(
+                &mut store,
+                ("/cosmos.bank.v1beta1.MsgSend", &pubkey(1)),
+                &Grant {
+                    grantee_kind: PubKeyKind::Secp256k1,
+                    expiry: None,
+                    max_calls: Some(5),
+                    spend_limit: Some(SpendLimit {
+                        denom: "uusdc".to_string(),
+                        remaining: 100u128.into(),
+                    }),
+                },
+            )
+            .unwrap();
+
+        let msgs = [
+            stargate_msg(
+                "/cosmos.bank.v1beta1.MsgSend",
+                encode_coin_msg("uusdc", 60),
+            ),
+            stargate_msg(
+                "/cosmos.bank.v1beta1.MsgSend",
+                encode_coin_msg("uusdc", 60),
+            ),
+        ];
+
+        // a single grant spanning both messages must not let 60 + 60 through
+        // against a limit of 100
+        let err = assert_has_grant(&store, &mock_env().block, &msgs, &pubkey(1)).unwrap_err();
+        assert!(matches!(err, ContractError::GrantSpendLimitExceeded { .. }));
+
+        let msgs = [
+            stargate_msg(
+                "/cosmos.bank.v1beta1.MsgSend",
+                encode_coin_msg("uusdc", 30),
+            ),
+            stargate_msg(
+                "/cosmos.bank.v1beta1.MsgSend",
+                encode_coin_msg("uusdc", 30),
+            ),
+        ];
+
+        let (_, updates) = assert_has_grant(&store, &mock_env().block, &msgs, &pubkey(1))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(updates.len(), 1);
+        let (_, grant) = &updates[0];
+        assert_eq!(grant.max_calls, Some(3));
+        assert_eq!(grant.spend_limit.as_ref().unwrap().remaining, 40u128.into());
+    }
+
+    #[test]
+    fn assert_has_grant_rejects_exhausted_grant() {
+        let mut store = MockStorage::new();
+        GRANTS
+            .save(
+                &mut store,
+                ("/cosmos.bank.v1beta1.MsgSend", &pubkey(1)),
+                &Grant {
+                    grantee_kind: PubKeyKind::Secp256k1,
+                    expiry: None,
+                    max_calls: Some(0),
+                    spend_limit: None,
+                },
+            )
+            .unwrap();
+
+        let msgs = [stargate_msg("/cosmos.bank.v1beta1.MsgSend", Binary::default())];
+        let err = assert_has_grant(&store, &mock_env().block, &msgs, &pubkey(1)).unwrap_err();
+        assert!(matches!(err, ContractError::GrantCallsExhausted { .. }));
+    }
+}
@@ -0,0 +1,10 @@
+pub mod contract;
+pub mod error;
+pub mod execute;
+pub mod msg;
+pub mod proto;
+pub mod query;
+pub mod state;
+
+pub const CONTRACT_NAME: &str = "crates.io:abstract-account-granter";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,148 @@
+use cosmwasm_std::{Binary, Uint128};
+use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The signature scheme a [`PubKey`] is interpreted under.
+///
+/// `Secp256r1` (P-256) is included so that WebAuthn/passkey-backed keys can
+/// sign directly for the account, alongside the `Secp256k1` keys used by
+/// most chain wallets and plain `Ed25519` keys.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PubKeyKind {
+    Secp256k1,
+    Ed25519,
+    Secp256r1,
+}
+
+/// A public key tagged with the scheme it must be verified under.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PubKey {
+    pub kind: PubKeyKind,
+    pub key: Binary,
+}
+
+/// A member of the account's weighted signer set.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+pub struct Signer {
+    pub kind: PubKeyKind,
+    pub weight: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InstantiateMsg {
+    /// The account's signer set, as `(pubkey, weight)` pairs.
+    pub signers: Vec<(PubKey, u64)>,
+    /// The combined weight a set of signatures must reach to authorize a tx.
+    pub threshold: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    Grant {
+        type_url: String,
+        grantee: Binary,
+        grantee_kind: PubKeyKind,
+        expiry: Option<Expiration>,
+        max_calls: Option<u64>,
+        spend_limit: Option<SpendLimit>,
+    },
+    Revoke {
+        type_url: String,
+        grantee: Binary,
+    },
+    AddSigner {
+        pubkey: PubKey,
+        weight: u64,
+    },
+    RemoveSigner {
+        pubkey: Binary,
+    },
+    UpdateThreshold {
+        threshold: u64,
+    },
+    GrantAll {
+        grantee: Binary,
+        grantee_kind: PubKeyKind,
+        expiry: Expiration,
+    },
+    RevokeAll {
+        grantee: Binary,
+    },
+    GrantBatch {
+        grants: Vec<GrantItem>,
+    },
+    RevokeBatch {
+        revocations: Vec<RevokeItem>,
+    },
+}
+
+/// A single grant within a [`ExecuteMsg::GrantBatch`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GrantItem {
+    pub type_url: String,
+    pub grantee: Binary,
+    pub grantee_kind: PubKeyKind,
+    pub expiry: Option<Expiration>,
+    pub max_calls: Option<u64>,
+    pub spend_limit: Option<SpendLimit>,
+}
+
+/// A single revocation within a [`ExecuteMsg::RevokeBatch`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RevokeItem {
+    pub type_url: String,
+    pub grantee: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Signers {},
+    Threshold {},
+    Grant {
+        type_url: String,
+        grantee: Binary,
+    },
+    Grants {
+        start_after: Option<(String, Binary)>,
+        limit: Option<u32>,
+    },
+    Operator {
+        grantee: Binary,
+    },
+    Operators {
+        start_after: Option<Binary>,
+        limit: Option<u32>,
+    },
+}
+
+/// A delegation of signing authority for a single `type_url` to a grantee key.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Grant {
+    pub grantee_kind: PubKeyKind,
+    pub expiry: Option<Expiration>,
+    /// Remaining number of messages this grant may authorize, decremented on
+    /// each use. `None` means unlimited.
+    pub max_calls: Option<u64>,
+    /// Remaining spend allowance in a single denom, decremented by the
+    /// amount moved by each authorized message. `None` means unlimited.
+    pub spend_limit: Option<SpendLimit>,
+}
+
+/// A remaining spend allowance for a [`Grant`], in a single denom.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SpendLimit {
+    pub denom: String,
+    pub remaining: Uint128,
+}
+
+/// A blanket, "authorize-all" grant: the grantee key may sign for any
+/// `type_url` until `expiry`, without a separate per-`type_url` [`Grant`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OperatorGrant {
+    pub grantee_kind: PubKeyKind,
+    pub expiry: Expiration,
+}
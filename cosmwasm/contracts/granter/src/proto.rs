@@ -0,0 +1,185 @@
+//! A tiny, special-cased protobuf reader used to enforce spend-limited
+//! grants without pulling in a full proto codegen pipeline.
+//!
+//! It only knows how to sum `Coin { denom: string = 1, amount: string = 2 }`
+//! entries appearing as top-level length-delimited fields of a message, which
+//! covers `cosmos.bank.v1beta1.MsgSend`'s `amount` field. A spend limit is a
+//! security boundary, so a message whose amount this reader can't identify
+//! is rejected outright rather than treated as moving zero of the limited
+//! denom — failing open would let an unrecognized message type bypass the
+//! cap entirely.
+
+use cosmwasm_std::{StdError, StdResult, Uint128};
+
+/// Sums the amount of `denom` carried by any top-level `Coin` fields in a
+/// raw protobuf-encoded stargate message value.
+///
+/// Returns an error if the message contains no field this reader recognizes
+/// as a `Coin`, since that means the spend limit can't be enforced against
+/// it at all.
+pub fn sum_coin_amount(value: &[u8], denom: &str) -> StdResult<Uint128> {
+    let mut total = Uint128::zero();
+    let mut saw_coin = false;
+    let mut pos = 0;
+
+    while pos < value.len() {
+        let (key, next) = read_varint(value, pos)?;
+        pos = next;
+
+        let wire_type = key & 0x7;
+        match wire_type {
+            0 => {
+                let (_, next) = read_varint(value, pos)?;
+                pos = next;
+            }
+            1 => pos = checked_add(pos, 8, value.len())?,
+            5 => pos = checked_add(pos, 4, value.len())?,
+            2 => {
+                let (len, next) = read_varint(value, pos)?;
+                pos = next;
+                let end = checked_add(pos, len as usize, value.len())?;
+                let field = &value[pos..end];
+
+                if let Some((coin_denom, amount)) = try_parse_coin(field) {
+                    saw_coin = true;
+                    if coin_denom == denom {
+                        total = total.checked_add(amount).map_err(|e| {
+                            StdError::generic_err(format!("coin amount overflow: {e}"))
+                        })?;
+                    }
+                }
+
+                pos = end;
+            }
+            _ => return Err(StdError::parse_err("StargateMsg", "unsupported wire type")),
+        }
+    }
+
+    if !saw_coin {
+        return Err(StdError::parse_err(
+            "StargateMsg",
+            "spend-limited grant: message contains no recognizable Coin field",
+        ));
+    }
+
+    Ok(total)
+}
+
+/// Tries to read `field` as a `Coin { denom, amount }` message, returning its
+/// denom and parsed amount regardless of which denom it is.
+fn try_parse_coin(field: &[u8]) -> Option<(&str, Uint128)> {
+    let mut coin_denom = None;
+    let mut coin_amount = None;
+    let mut pos = 0;
+
+    while pos < field.len() {
+        let (key, next) = read_varint(field, pos).ok()?;
+        pos = next;
+
+        if key & 0x7 != 2 {
+            return None;
+        }
+
+        let (len, next) = read_varint(field, pos).ok()?;
+        pos = next;
+        let end = pos.checked_add(len as usize)?;
+        if end > field.len() {
+            return None;
+        }
+        let segment = std::str::from_utf8(&field[pos..end]).ok()?;
+        pos = end;
+
+        match key >> 3 {
+            1 => coin_denom = Some(segment),
+            2 => coin_amount = Some(segment),
+            _ => return None,
+        }
+    }
+
+    let amount = coin_amount?.parse::<u128>().ok().map(Uint128::new)?;
+    Some((coin_denom?, amount))
+}
+
+fn checked_add(pos: usize, len: usize, max: usize) -> StdResult<usize> {
+    let end = pos
+        .checked_add(len)
+        .ok_or_else(|| StdError::parse_err("StargateMsg", "length overflow"))?;
+    if end > max {
+        return Err(StdError::parse_err("StargateMsg", "truncated field"));
+    }
+    Ok(end)
+}
+
+fn read_varint(bytes: &[u8], mut pos: usize) -> StdResult<(u64, usize)> {
+    let mut value = 0u64;
+    let mut shift = 0;
+
+    loop {
+        let byte = *bytes
+            .get(pos)
+            .ok_or_else(|| StdError::parse_err("StargateMsg", "truncated varint"))?;
+        pos += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok((value, pos));
+        }
+
+        shift += 7;
+        if shift >= 64 {
+            return Err(StdError::parse_err("StargateMsg", "varint too long"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_bytes_field(field_number: u32, bytes: &[u8]) -> Vec<u8> {
+        let mut out = encode_varint(((field_number << 3) | 2) as u64);
+        out.extend(encode_varint(bytes.len() as u64));
+        out.extend_from_slice(bytes);
+        out
+    }
+
+    fn encode_varint(mut value: u64) -> Vec<u8> {
+        let mut out = Vec::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                return out;
+            }
+            out.push(byte | 0x80);
+        }
+    }
+
+    fn encode_coin_msg(denom: &str, amount: u128) -> Vec<u8> {
+        let mut coin = encode_bytes_field(1, denom.as_bytes());
+        coin.extend(encode_bytes_field(2, amount.to_string().as_bytes()));
+        encode_bytes_field(1, &coin)
+    }
+
+    #[test]
+    fn sums_matching_denom() {
+        let value = encode_coin_msg("uusdc", 42);
+        assert_eq!(sum_coin_amount(&value, "uusdc").unwrap(), Uint128::new(42));
+    }
+
+    #[test]
+    fn ignores_non_matching_denom() {
+        let value = encode_coin_msg("uusdc", 42);
+        assert_eq!(sum_coin_amount(&value, "uatom").unwrap(), Uint128::zero());
+    }
+
+    #[test]
+    fn rejects_message_with_no_recognizable_coin_field() {
+        // a lone top-level varint field (wire type 0), as in a message with
+        // no Coin-shaped field at all
+        let mut value = encode_varint(0x08);
+        value.extend(encode_varint(5));
+        assert!(sum_coin_amount(&value, "uusdc").is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use cosmwasm_std::{Binary, Order, StdResult, Storage};
+use cw_storage_plus::Bound;
+
+use crate::{
+    msg::{Grant, OperatorGrant, Signer},
+    state::{GRANTS, OPERATORS, SIGNERS, THRESHOLD},
+};
+
+const DEFAULT_LIMIT: u32 = 30;
+const MAX_LIMIT: u32 = 100;
+
+pub fn signers(store: &dyn Storage) -> StdResult<Vec<(Binary, Signer)>> {
+    SIGNERS
+        .range(store, None, None, Order::Ascending)
+        .collect()
+}
+
+pub fn threshold(store: &dyn Storage) -> StdResult<u64> {
+    THRESHOLD.load(store)
+}
+
+pub fn grant(store: &dyn Storage, type_url: String, grantee: Binary) -> StdResult<Option<Grant>> {
+    GRANTS.may_load(store, (&type_url, &grantee))
+}
+
+pub fn grants(
+    store: &dyn Storage,
+    start_after: Option<(String, Binary)>,
+    limit: Option<u32>,
+) -> StdResult<Vec<((String, Binary), Grant)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|(type_url, grantee)| Bound::exclusive((type_url.as_str(), grantee)));
+
+    GRANTS
+        .range(store, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+pub fn operator(store: &dyn Storage, grantee: Binary) -> StdResult<Option<OperatorGrant>> {
+    OPERATORS.may_load(store, &grantee)
+}
+
+pub fn operators(
+    store: &dyn Storage,
+    start_after: Option<Binary>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Binary, OperatorGrant)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    OPERATORS
+        .range(store, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
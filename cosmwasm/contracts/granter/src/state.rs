@@ -0,0 +1,9 @@
+use cosmwasm_std::Binary;
+use cw_storage_plus::{Item, Map};
+
+use crate::msg::{Grant, OperatorGrant, Signer};
+
+pub const SIGNERS: Map<&Binary, Signer> = Map::new("signers");
+pub const THRESHOLD: Item<u64> = Item::new("threshold");
+pub const GRANTS: Map<(&str, &Binary), Grant> = Map::new("grants");
+pub const OPERATORS: Map<&Binary, OperatorGrant> = Map::new("operators");